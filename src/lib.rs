@@ -55,7 +55,12 @@
 
 extern crate alloc;
 
-use alloc::{borrow::Cow, str::from_utf8, string::String, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    str::{from_utf8, from_utf8_unchecked},
+    string::String,
+    vec::Vec,
+};
 use core::fmt;
 
 /// Converts a slice of bytes to a string slice.
@@ -99,88 +104,310 @@ use core::fmt;
 pub fn decode(bytes: &[u8]) -> Result<Cow<str>, Error> {
     from_utf8(bytes)
         .map(Cow::Borrowed)
-        .or_else(|_| decode_cesu8(bytes).map(Cow::Owned))
+        .or_else(|_| decode_cesu8(bytes, false).map(Cow::Owned))
+}
+
+/// Converts a slice of Java Modified UTF-8 (MUTF-8) bytes to a string slice.
+///
+/// MUTF-8 is the variant of CESU-8 used by the JVM class file format, DEX, the
+/// JNI `GetStringUTFChars` family and `DataInput`/`DataOutput`. It differs from
+/// plain CESU-8 in exactly one way: the NUL code point `U+0000` is encoded as
+/// the two bytes `0xC0 0x80` rather than a literal `0x00` byte, so that a NUL
+/// never appears in the encoded stream.
+///
+/// Like [`decode`], a borrow is returned when the input is already valid UTF-8
+/// with no embedded NUL; otherwise the bytes are decoded into an owned string.
+///
+/// # Errors
+///
+/// Returns [`cesu8::Error`](Error) if the input is invalid MUTF-8 data. In
+/// addition to the cases rejected by [`decode`], a literal `0x00` byte in the
+/// input is invalid MUTF-8 and yields an error.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::borrow::Cow;
+///
+/// # fn main() -> Result<(), cesu8::Error> {
+/// // 'U+0000' is encoded as the two bytes '0xC0 0x80' rather than a NUL byte.
+/// let mutf8_data = &[0xC0, 0x80];
+/// assert_eq!(cesu8::decode_modified(mutf8_data)?, Cow::<str>::Owned("\0".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn decode_modified(bytes: &[u8]) -> Result<Cow<str>, Error> {
+    match from_utf8(bytes) {
+        // A literal NUL is valid UTF-8 but never valid MUTF-8, so the borrow
+        // fast path only applies when the input contains no NUL byte.
+        Ok(str) if !bytes.contains(&0x00) => Ok(Cow::Borrowed(str)),
+        _ => decode_cesu8(bytes, true).map(Cow::Owned),
+    }
+}
+
+/// Converts a slice of bytes to a string slice, replacing invalid CESU-8 data.
+///
+/// This mirrors [`String::from_utf8_lossy`]: when the input is valid UTF-8 it
+/// is borrowed unchanged. Otherwise it is decoded as CESU-8, and any invalid
+/// sequence (including a four-byte UTF-8 sequence, which is never valid
+/// CESU-8) is replaced with the replacement character `U+FFFD` rather than
+/// returning an [`Error`]. This makes it suitable for decoding untrusted or truncated
+/// CESU-8, such as network frames or corrupted class files, where aborting the
+/// whole buffer is unacceptable.
+///
+/// Replacement follows the "substitution of maximal subparts" behavior of
+/// robust UTF-8 decoders: one replacement character is emitted per malformed
+/// sequence and scanning resumes at the next byte that could begin a new
+/// sequence, rather than discarding the remainder of the input.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::borrow::Cow;
+///
+/// // Valid data is borrowed unchanged.
+/// assert_eq!(cesu8::decode_lossy(b"Hello, world!"), Cow::Borrowed("Hello, world!"));
+///
+/// // A stray continuation byte becomes a single replacement character.
+/// assert_eq!(cesu8::decode_lossy(&[0x80]), Cow::<str>::Owned("\u{FFFD}".to_string()));
+/// ```
+#[must_use]
+#[inline]
+pub fn decode_lossy(bytes: &[u8]) -> Cow<str> {
+    match from_utf8(bytes) {
+        Ok(str) => Cow::Borrowed(str),
+        Err(_) => Cow::Owned(decode_cesu8_lossy(bytes)),
+    }
 }
 
 #[inline(never)]
 #[cold]
-#[allow(clippy::unnested_or_patterns)] // this hurts readability otherwise
-fn decode_cesu8(bytes: &[u8]) -> Result<String, Error> {
-    let mut decoded = Vec::with_capacity(bytes.len());
-    let mut iter = bytes.iter();
+fn decode_cesu8_lossy(bytes: &[u8]) -> String {
+    const REPLACEMENT: [u8; 3] = [0xEF, 0xBF, 0xBD];
 
-    macro_rules! err {
-        () => {{
-            return Err(Error);
-        }};
-    }
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut state = DFA_ACCEPT;
+    let mut start = 0;
+    let mut index = 0;
 
-    macro_rules! next {
-        () => {
-            match iter.next() {
-                Some(&byte) => byte,
-                None => err!(),
+    while index < bytes.len() {
+        let next = DFA_TRANS[(state + DFA_CLASS[bytes[index] as usize]) as usize];
+        if next == DFA_REJECT {
+            decoded.extend_from_slice(&REPLACEMENT);
+            // A byte that is invalid at a scalar boundary is consumed; one that
+            // truncates a multi-byte sequence is reconsidered as a fresh start.
+            if state == DFA_ACCEPT {
+                index += 1;
             }
-        };
-    }
+            state = DFA_ACCEPT;
+            start = index;
+            continue;
+        }
 
-    macro_rules! next_continuation {
-        () => {{
-            let byte = next!();
-            if is_continuation_byte(byte) {
-                byte
+        state = next;
+        index += 1;
+
+        if state == DFA_ACCEPT {
+            let sequence = &bytes[start..index];
+            if sequence.len() == SURROGATE_PAIR_WIDTH {
+                decoded.extend_from_slice(&decode_surrogate_pair(
+                    sequence[1],
+                    sequence[2],
+                    sequence[4],
+                    sequence[5],
+                ));
             } else {
-                err!();
+                decoded.extend_from_slice(sequence);
             }
-        }};
+            start = index;
+        }
     }
 
-    while let Some(&first) = iter.next() {
-        if first <= MAX_ASCII_CODE_POINT {
-            decoded.push(first);
-        } else {
-            let width = match utf8_char_width(first) {
-                Some(v) => v,
-                None => err!(),
-            };
-            let second = next_continuation!();
-            match width {
-                2 => decoded.extend_from_slice(&[first, second]),
-                3 => {
-                    let third = next_continuation!();
-                    match (first, second) {
-                        (0xE0, 0xA0..=0xBF)
-                        | (0xE1..=0xEC, 0x80..=0xBF)
-                        | (0xED, 0x80..=0x9F)
-                        | (0xEE..=0xEF, 0x80..=0xBF) => {
-                            decoded.extend_from_slice(&[first, second, third]);
-                        }
-                        (0xED, 0xA0..=0xAF) => {
-                            let fourth = next!();
-                            if fourth != 0xED {
-                                err!();
-                            }
-                            let fifth = next_continuation!();
-                            if !(0xB0..=0xBF).contains(&fifth) {
-                                err!();
-                            }
-                            let sixth = next_continuation!();
-                            decoded.extend_from_slice(&decode_surrogate_pair(
-                                second, third, fifth, sixth,
-                            ));
-                        }
-                        _ => err!(),
-                    }
+    // A sequence left incomplete at the end of the input is a single malformed
+    // subpart and is replaced as well.
+    if state != DFA_ACCEPT {
+        decoded.extend_from_slice(&REPLACEMENT);
+    }
+
+    debug_assert!(from_utf8(&decoded).is_ok());
+    unsafe { String::from_utf8_unchecked(decoded) }
+}
+
+#[inline(never)]
+#[cold]
+fn decode_cesu8(bytes: &[u8], modified: bool) -> Result<String, Error> {
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut state = DFA_ACCEPT;
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        // MUTF-8 encodes NUL as the overlong `0xC0 0x80` and forbids a literal
+        // `0x00`; neither fits the CESU-8 DFA, so they are handled up front
+        // while the automaton sits at a scalar boundary.
+        if modified {
+            if byte == 0x00 {
+                return Err(Error);
+            }
+            if state == DFA_ACCEPT && byte == 0xC0 {
+                if bytes.get(index + 1) != Some(&0x80) {
+                    return Err(Error);
                 }
-                _ => err!(),
+                decoded.push(0x00);
+                index += 2;
+                start = index;
+                continue;
             }
         }
+
+        state = DFA_TRANS[(state + DFA_CLASS[byte as usize]) as usize];
+        if state == DFA_REJECT {
+            return Err(Error);
+        }
+        index += 1;
+
+        if state == DFA_ACCEPT {
+            let sequence = &bytes[start..index];
+            if sequence.len() == SURROGATE_PAIR_WIDTH {
+                decoded.extend_from_slice(&decode_surrogate_pair(
+                    sequence[1],
+                    sequence[2],
+                    sequence[4],
+                    sequence[5],
+                ));
+            } else {
+                // Any accepted sequence shorter than a surrogate pair is already
+                // valid UTF-8 and can be copied verbatim.
+                decoded.extend_from_slice(sequence);
+            }
+            start = index;
+        }
+    }
+
+    if state != DFA_ACCEPT {
+        return Err(Error);
     }
 
     debug_assert!(from_utf8(&decoded).is_ok());
     Ok(unsafe { String::from_utf8_unchecked(decoded) })
 }
 
+const SURROGATE_PAIR_WIDTH: usize = 6;
+
+/// An incremental decoder for CESU-8 data arriving in arbitrary chunks.
+///
+/// Unlike [`decode`], which requires the whole input up front, `Cesu8Decoder`
+/// lets callers reading from a socket or file feed byte slices one at a time.
+/// Any partial multi-byte sequence left at the end of a chunk — including a
+/// six-byte surrogate pair split at any of its internal boundaries — is carried
+/// over in a small fixed-size buffer and resumed on the next call.
+///
+/// # Examples
+///
+/// ```
+/// use cesu8::Cesu8Decoder;
+///
+/// # fn main() -> Result<(), cesu8::Error> {
+/// // A surrogate pair split across two chunks still decodes correctly.
+/// let mut decoder = Cesu8Decoder::new();
+/// let mut out = String::new();
+/// decoder.decode_chunk(&[0xED, 0xA0, 0x81], &mut out)?;
+/// decoder.decode_chunk(&[0xED, 0xB0, 0x80], &mut out)?;
+/// decoder.finish()?;
+/// assert_eq!(out, "\u{10400}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cesu8Decoder {
+    state: u8,
+    sequence: [u8; SURROGATE_PAIR_WIDTH],
+    sequence_len: usize,
+}
+
+impl Cesu8Decoder {
+    /// Creates a new decoder positioned at a scalar boundary.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Cesu8Decoder {
+        Cesu8Decoder {
+            state: DFA_ACCEPT,
+            sequence: [0; SURROGATE_PAIR_WIDTH],
+            sequence_len: 0,
+        }
+    }
+
+    /// Decodes one chunk of CESU-8 data, appending the result to `out`.
+    ///
+    /// Bytes belonging to a sequence that is not yet complete are retained
+    /// internally and decoded once the remaining bytes arrive on a later call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] as soon as a byte cannot continue the current sequence.
+    /// Four-byte UTF-8 sequences are rejected, even though [`decode`] accepts
+    /// them when its whole input is valid UTF-8.
+    pub fn decode_chunk(&mut self, bytes: &[u8], out: &mut String) -> Result<(), Error> {
+        for &byte in bytes {
+            let next = DFA_TRANS[(self.state + DFA_CLASS[byte as usize]) as usize];
+            if next == DFA_REJECT {
+                return Err(Error);
+            }
+            self.sequence[self.sequence_len] = byte;
+            self.sequence_len += 1;
+            self.state = next;
+
+            if next == DFA_ACCEPT {
+                let sequence = &self.sequence[..self.sequence_len];
+                if sequence.len() == SURROGATE_PAIR_WIDTH {
+                    let decoded = decode_surrogate_pair(
+                        sequence[1],
+                        sequence[2],
+                        sequence[4],
+                        sequence[5],
+                    );
+                    out.push_str(unsafe { from_utf8_unchecked(&decoded) });
+                } else {
+                    out.push_str(unsafe { from_utf8_unchecked(sequence) });
+                }
+                self.sequence_len = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finishes decoding, reporting whether the stream ended cleanly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the decoder still holds an incomplete multi-byte
+    /// sequence, i.e. the stream ended in the middle of a scalar.
+    #[inline]
+    pub fn finish(self) -> Result<(), Error> {
+        if self.state == DFA_ACCEPT {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl Default for Cesu8Decoder {
+    #[inline]
+    fn default() -> Cesu8Decoder {
+        Cesu8Decoder::new()
+    }
+}
+
 #[inline]
 fn decode_surrogate_pair(second: u8, third: u8, fifth: u8, sixth: u8) -> [u8; 4] {
     let surrogate1 = decode_surrogate(second, third);
@@ -243,24 +470,62 @@ pub fn encode(str: &str) -> Cow<[u8]> {
     if is_valid(str) {
         Cow::Borrowed(str.as_bytes())
     } else {
-        Cow::Owned(encode_cesu8(str))
+        Cow::Owned(encode_cesu8(str, false))
+    }
+}
+
+/// Converts a string slice to Java Modified UTF-8 (MUTF-8) bytes.
+///
+/// This behaves like [`encode`] but additionally encodes the NUL code point
+/// `U+0000` as the two bytes `0xC0 0x80`, matching the encoding used by the JVM
+/// class file format, DEX and the JNI. A borrow is returned only when the
+/// string contains neither a supplementary code point nor a NUL.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::borrow::Cow;
+///
+/// // A NUL becomes the two-byte overlong sequence '0xC0 0x80'.
+/// assert_eq!(cesu8::encode_modified("\0"), Cow::<[u8]>::Owned(vec![0xC0, 0x80]));
+/// ```
+#[must_use]
+#[inline]
+pub fn encode_modified(str: &str) -> Cow<[u8]> {
+    if is_valid_modified(str) {
+        Cow::Borrowed(str.as_bytes())
+    } else {
+        Cow::Owned(encode_cesu8(str, true))
     }
 }
 
 #[must_use]
 #[inline(never)]
 #[cold]
-fn encode_cesu8(str: &str) -> Vec<u8> {
+fn encode_cesu8(str: &str, modified: bool) -> Vec<u8> {
     let bytes = str.as_bytes();
-    let capacity = len(str);
+    let capacity = len_cesu8(str, modified);
     let mut encoded = Vec::with_capacity(capacity);
     let mut index = 0;
 
     while index < bytes.len() {
         let byte = bytes[index];
         if byte <= MAX_ASCII_CODE_POINT {
-            encoded.push(byte);
-            index += 1;
+            if modified {
+                if byte == 0x00 {
+                    encoded.extend_from_slice(&[0xC0, 0x80]);
+                } else {
+                    encoded.push(byte);
+                }
+                index += 1;
+            } else {
+                let run = first_non_ascii(&bytes[index..]);
+                encoded.extend_from_slice(&bytes[index..index + run]);
+                index += run;
+            }
         } else {
             let width = utf8_char_width(byte).unwrap();
             let slice_range = index..index + width;
@@ -321,14 +586,47 @@ fn to_surrogate_pair(code_point: u32) -> [u16; 2] {
 /// ```
 #[must_use]
 pub fn len(str: &str) -> usize {
+    len_cesu8(str, false)
+}
+
+/// Returns how many bytes in Java Modified UTF-8 (MUTF-8) are required to encode
+/// a string slice.
+///
+/// This matches [`len`] except that each `U+0000` costs two bytes rather than
+/// one, because MUTF-8 encodes NUL as the overlong sequence `0xC0 0x80`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// // A NUL is a single byte in UTF-8 but two bytes in MUTF-8.
+/// assert_eq!(cesu8::len_modified("\0"), 2);
+///
+/// // Any other code point costs the same as it does in CESU-8.
+/// assert_eq!(cesu8::len_modified("\u{10000}"), 6);
+/// ```
+#[must_use]
+pub fn len_modified(str: &str) -> usize {
+    len_cesu8(str, true)
+}
+
+#[must_use]
+fn len_cesu8(str: &str, modified: bool) -> usize {
     let bytes = str.as_bytes();
     let mut len = 0;
     let mut index = 0;
     while index < bytes.len() {
         let byte = bytes[index];
         if byte <= MAX_ASCII_CODE_POINT {
-            len += 1;
-            index += 1;
+            if modified {
+                len += if byte == 0x00 { 2 } else { 1 };
+                index += 1;
+            } else {
+                let run = first_non_ascii(&bytes[index..]);
+                len += run;
+                index += run;
+            }
         } else {
             // SAFETY: Valid UTF-8 will never yield a `None` value:
             let width = unsafe { utf8_char_width(byte).unwrap_unchecked() };
@@ -365,19 +663,290 @@ pub fn len(str: &str) -> usize {
 /// ```
 #[must_use]
 pub fn is_valid(str: &str) -> bool {
-    for byte in str.bytes() {
-        if is_continuation_byte(byte) {
-            continue;
-        }
-        if let Some(width) = utf8_char_width(byte) {
-            if width > CESU8_MAX_CHAR_WIDTH {
-                return false;
+    is_valid_cesu8(str, false)
+}
+
+/// Returns `true` if a string slice contains UTF-8 data that is also valid Java
+/// Modified UTF-8 (MUTF-8).
+///
+/// This mirrors [`is_valid`] but additionally returns `false` for any string
+/// containing a NUL code point, since MUTF-8 must re-encode `U+0000` as
+/// `0xC0 0x80` and therefore [`&str.as_bytes()`](str::as_bytes) is not a valid
+/// MUTF-8 representation of such a string.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// assert!(cesu8::is_valid_modified("Hello, world!"));
+///
+/// // A NUL must be re-encoded, so it is not directly valid MUTF-8.
+/// assert!(!cesu8::is_valid_modified("\0"));
+/// ```
+#[must_use]
+pub fn is_valid_modified(str: &str) -> bool {
+    is_valid_cesu8(str, true)
+}
+
+#[must_use]
+fn is_valid_cesu8(str: &str, modified: bool) -> bool {
+    let bytes = str.as_bytes();
+    if modified && bytes.contains(&0x00) {
+        return false;
+    }
+    let mut state = DFA_ACCEPT;
+    let mut index = 0;
+    while index < bytes.len() {
+        // At a scalar boundary, bulk-skip any run of ASCII before resuming the
+        // per-byte state machine.
+        if state == DFA_ACCEPT {
+            index += first_non_ascii(&bytes[index..]);
+            if index >= bytes.len() {
+                break;
             }
-        } else {
+        }
+        state = DFA_TRANS[(state + DFA_CLASS[bytes[index] as usize]) as usize];
+        if state == DFA_REJECT {
             return false;
         }
+        index += 1;
+    }
+    state == DFA_ACCEPT
+}
+
+/// A Unicode code point in the range `U+0000..=U+10FFFF`.
+///
+/// Unlike [`char`], a `CodePoint` may hold an unpaired surrogate in the range
+/// `U+D800..=U+DFFF`. This makes it suitable for representing the ill-formed
+/// UTF-16 found in Windows filenames, JavaScript strings and other WTF-8 data,
+/// which [`char`] and [`str`] are forbidden from containing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodePoint {
+    value: u32,
+}
+
+impl CodePoint {
+    /// Creates a `CodePoint` from a `u32`, or returns `None` if the value is
+    /// greater than `U+10FFFF`.
+    ///
+    /// Surrogate values in the range `U+D800..=U+DFFF` are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cesu8::CodePoint;
+    ///
+    /// assert!(CodePoint::from_u32(0x1F600).is_some());
+    /// // An unpaired surrogate is a valid code point, even though it is not a
+    /// // valid 'char'.
+    /// assert!(CodePoint::from_u32(0xD800).is_some());
+    /// assert!(CodePoint::from_u32(0x11_0000).is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_u32(value: u32) -> Option<CodePoint> {
+        if value <= 0x10_FFFF {
+            Some(CodePoint { value })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a `CodePoint` from a `char`.
+    ///
+    /// This conversion is always valid, as every `char` is a code point.
+    #[must_use]
+    #[inline]
+    pub fn from_char(c: char) -> CodePoint {
+        CodePoint { value: c as u32 }
+    }
+
+    /// Returns the numeric value of this code point.
+    #[must_use]
+    #[inline]
+    pub fn to_u32(self) -> u32 {
+        self.value
+    }
+
+    /// Returns `true` if this code point is a surrogate in the range
+    /// `U+D800..=U+DFFF`.
+    #[must_use]
+    #[inline]
+    pub fn is_surrogate(self) -> bool {
+        (0xD800..=0xDFFF).contains(&self.value)
+    }
+
+    /// Converts this code point to a `char`, replacing an unpaired surrogate
+    /// with the replacement character `U+FFFD`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cesu8::CodePoint;
+    ///
+    /// assert_eq!(CodePoint::from_char('a').to_char_lossy(), 'a');
+    /// assert_eq!(CodePoint::from_u32(0xD800).unwrap().to_char_lossy(), '\u{FFFD}');
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_char_lossy(self) -> char {
+        char::from_u32(self.value).unwrap_or('\u{FFFD}')
+    }
+}
+
+impl From<char> for CodePoint {
+    #[inline]
+    fn from(c: char) -> CodePoint {
+        CodePoint::from_char(c)
+    }
+}
+
+/// Decodes a slice of WTF-8 (or CESU-8) bytes into an iterator of [`CodePoint`]s.
+///
+/// This is the generalization of [`decode`] to ill-formed UTF-16: an adjacent
+/// high and low surrogate pair is combined into a single supplementary code
+/// point, while an isolated surrogate is preserved as its own `CodePoint`
+/// rather than rejected. This mirrors the well-formedness rules of WTF-8 and
+/// makes the crate usable for OS-string and UTF-16-interchange scenarios.
+///
+/// Any byte that cannot begin a well-formed sequence yields the replacement
+/// code point `U+FFFD` and scanning resumes at the next byte.
+///
+/// # Examples
+///
+/// ```
+/// use cesu8::CodePoint;
+///
+/// // A full surrogate pair is combined into one supplementary code point.
+/// let pair = &[0xED, 0xA0, 0x81, 0xED, 0xB0, 0x80];
+/// let points: Vec<_> = cesu8::code_points(pair).collect();
+/// assert_eq!(points, vec![CodePoint::from_char('\u{10400}')]);
+///
+/// // An unpaired high surrogate is preserved as a single code point.
+/// let lone = &[0xED, 0xA0, 0x81];
+/// let points: Vec<_> = cesu8::code_points(lone).collect();
+/// assert_eq!(points, vec![CodePoint::from_u32(0xD801).unwrap()]);
+/// ```
+#[must_use]
+#[inline]
+pub fn code_points(bytes: &[u8]) -> CodePoints<'_> {
+    CodePoints { bytes, index: 0 }
+}
+
+/// An iterator over the [`CodePoint`]s of a WTF-8 (or CESU-8) byte slice.
+///
+/// This is created by the [`code_points`] function.
+#[derive(Clone, Debug)]
+pub struct CodePoints<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl Iterator for CodePoints<'_> {
+    type Item = CodePoint;
+
+    fn next(&mut self) -> Option<CodePoint> {
+        let first = *self.bytes.get(self.index)?;
+
+        // A byte that cannot begin a sequence is replaced, advancing by one so
+        // that scanning can resume at the next candidate lead byte.
+        let replacement = CodePoint { value: 0xFFFD };
+        macro_rules! bail {
+            () => {{
+                self.index += 1;
+                return Some(replacement);
+            }};
+        }
+
+        match utf8_char_width(first) {
+            Some(1) => {
+                self.index += 1;
+                Some(CodePoint {
+                    value: u32::from(first),
+                })
+            }
+            Some(2) => {
+                let Some(value) = self.decode(first, 2) else {
+                    bail!()
+                };
+                Some(CodePoint { value })
+            }
+            Some(3) => {
+                let Some(value) = self.decode(first, 3) else {
+                    bail!()
+                };
+                // A high surrogate followed by a low surrogate forms a single
+                // supplementary code point; otherwise it stands alone.
+                if (0xD800..=0xDBFF).contains(&value) {
+                    if let Some(low) = self.peek_low_surrogate() {
+                        self.index += 3;
+                        let combined = 0x10000 + ((value - 0xD800) << 10 | (low - 0xDC00));
+                        return Some(CodePoint { value: combined });
+                    }
+                }
+                Some(CodePoint { value })
+            }
+            Some(4) => {
+                let Some(value) = self.decode(first, 4) else {
+                    bail!()
+                };
+                Some(CodePoint { value })
+            }
+            _ => bail!(),
+        }
+    }
+}
+
+impl CodePoints<'_> {
+    /// Decodes the `width`-byte sequence beginning at the cursor, advancing it
+    /// on success. Returns `None` — leaving the cursor untouched — if a
+    /// continuation byte is missing or invalid, if the sequence is overlong, or
+    /// if it decodes above `U+10FFFF`, matching the rejections of the strict
+    /// DFA so that the caller can substitute `U+FFFD`.
+    fn decode(&mut self, first: u8, width: usize) -> Option<u32> {
+        if self.index + width > self.bytes.len() {
+            return None;
+        }
+        let lead_mask = 0x7Fu8 >> width;
+        let mut value = u32::from(first & lead_mask);
+        for offset in 1..width {
+            let byte = self.bytes[self.index + offset];
+            if !is_continuation_byte(byte) {
+                return None;
+            }
+            value = (value << 6) | u32::from(byte & 0b0011_1111);
+        }
+        // Reject overlong encodings and anything beyond the Unicode range;
+        // surrogates are intentionally still permitted in WTF-8.
+        let minimum = match width {
+            2 => 0x80,
+            3 => 0x800,
+            _ => 0x1_0000,
+        };
+        if value < minimum || value > 0x10_FFFF {
+            return None;
+        }
+        self.index += width;
+        Some(value)
+    }
+
+    /// Returns the low surrogate value of a three-byte sequence immediately
+    /// following the cursor, without advancing it.
+    fn peek_low_surrogate(&self) -> Option<u32> {
+        let bytes = self.bytes.get(self.index..self.index + 3)?;
+        if bytes[0] != 0xED
+            || !(0xB0..=0xBF).contains(&bytes[1])
+            || !is_continuation_byte(bytes[2])
+        {
+            return None;
+        }
+        Some(
+            0xD000
+                | (u32::from(bytes[1] & 0b0011_1111) << 6)
+                | u32::from(bytes[2] & 0b0011_1111),
+        )
     }
-    true
 }
 
 const CESU8_MAX_CHAR_WIDTH: usize = 3;
@@ -402,6 +971,98 @@ fn utf8_char_width(byte: u8) -> Option<usize> {
 
 const MAX_ASCII_CODE_POINT: u8 = 0x7F;
 
+/// Returns the length of the leading run of ASCII bytes in `bytes`.
+///
+/// Real inputs are overwhelmingly ASCII, so this scans a word at a time and
+/// tests the high bit of every byte in the word at once, only dropping to the
+/// per-byte state machine once a non-ASCII byte is reached. The word-at-a-time
+/// loop keeps the crate `no_std`-compatible without any target-specific SIMD
+/// intrinsics, while still letting the ASCII-heavy `Cow::Borrowed` path run
+/// close to `memcpy` speed.
+#[inline]
+fn first_non_ascii(bytes: &[u8]) -> usize {
+    const WORD: usize = core::mem::size_of::<usize>();
+    // `0x8080..80`: the high bit of every byte lane in a word.
+    const HIGH_MASK: usize = usize::MAX / 0xFF * 0x80;
+
+    let mut index = 0;
+    while index + WORD <= bytes.len() {
+        let mut lanes = [0u8; WORD];
+        lanes.copy_from_slice(&bytes[index..index + WORD]);
+        if usize::from_ne_bytes(lanes) & HIGH_MASK != 0 {
+            break;
+        }
+        index += WORD;
+    }
+    while index < bytes.len() && bytes[index] <= MAX_ASCII_CODE_POINT {
+        index += 1;
+    }
+    index
+}
+
+// A table-driven decoder in the spirit of Björn Höhrmann's UTF-8 DFA, extended
+// to recognize the six-byte CESU-8 surrogate-pair sequence so that validation
+// and decoding share a single pass with no per-byte width lookups. Each byte is
+// mapped to one of `CLASS_COUNT` classes and the automaton advances with
+// `state = DFA_TRANS[state + DFA_CLASS[byte]]`. State IDs are pre-multiplied by
+// `CLASS_COUNT` so the core step needs no multiply; `DFA_REJECT` is a trap and
+// `DFA_ACCEPT` marks a completed scalar and the start of the next one.
+
+/// Number of byte classes used to index the transition table.
+const CLASS_COUNT: u8 = 10;
+
+/// Trap state entered on invalid input; never left once reached.
+const DFA_REJECT: u8 = 0;
+
+/// Scalar-boundary state: the start state and the state after a full sequence.
+const DFA_ACCEPT: u8 = CLASS_COUNT;
+
+/// Maps each byte to its class (see the state comments in [`DFA_TRANS`]).
+#[rustfmt::skip]
+const DFA_CLASS: [u8; 256] = [
+    // 0x00..=0x7F: ASCII
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    // 0x80..=0x8F and 0x90..=0x9F: continuation bytes (low halves)
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    // 0xA0..=0xAF and 0xB0..=0xBF: continuation bytes (high halves)
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    // 0xC0..=0xC1 invalid, 0xC2..=0xDF two-byte leads
+    9, 9, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    // 0xE0 / 0xE1..=0xEC / 0xED / 0xEE..=0xEF three-byte leads
+    6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8, 7, 7,
+    // 0xF0..=0xFF: invalid in CESU-8 (four-byte UTF-8 is never valid CESU-8)
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+];
+
+/// Maps `state + class` to the next state (pre-multiplied by [`CLASS_COUNT`]).
+///
+/// Classes are: `0` ASCII, `1` cont `80..=8F`, `2` cont `90..=9F`, `3` cont
+/// `A0..=AF`, `4` cont `B0..=BF`, `5` two-byte lead, `6` `E0`, `7` generic
+/// three-byte lead, `8` `ED`, `9` invalid.
+#[rustfmt::skip]
+const DFA_TRANS: [u8; 90] = [
+    //  ASCII  c8x   c9x  cAx  cBx  ld2  E0   ld3  ED   inv
+    0,    0,    0,   0,   0,   0,   0,   0,   0,   0, // 0 REJECT (trap)
+    10,   0,    0,   0,   0,   20,  30,  40,  50,  0, // 10 ACCEPT / start
+    0,    10,   10,  10,  10,  0,   0,   0,   0,   0, // 20 expect 1 continuation
+    0,    0,    0,   20,  20,  0,   0,   0,   0,   0, // 30 after E0 (need A0..=BF)
+    0,    20,   20,  20,  20,  0,   0,   0,   0,   0, // 40 after generic 3-byte lead
+    0,    20,   20,  60,  0,   0,   0,   0,   0,   0, // 50 after ED (A0..=AF => surrogate)
+    0,    70,   70,  70,  70,  0,   0,   0,   0,   0, // 60 high surrogate tail
+    0,    0,    0,   0,   0,   0,   0,   0,   80,  0, // 70 expect ED of low surrogate
+    0,    0,    0,   0,   20,  0,   0,   0,   0,   0, // 80 low surrogate (need B0..=BF)
+];
+
 /// An error thrown by [`decode`] when the input is invalid CESU-8 data.
 ///
 /// This type does not support transmission of an error other than that an error
@@ -419,3 +1080,116 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn code_points_rejects_overlong_and_out_of_range() {
+        let fffd = CodePoint::from_u32(0xFFFD).unwrap();
+        // Overlong '/', overlong NUL and a value above U+10FFFF must not leak
+        // through; each yields the replacement code point instead.
+        assert_eq!(code_points(&[0xE0, 0x80, 0xAF]).next(), Some(fffd));
+        assert_eq!(code_points(&[0xF0, 0x80, 0x80, 0x80]).next(), Some(fffd));
+        assert_eq!(code_points(&[0xF4, 0x90, 0x80, 0x80]).next(), Some(fffd));
+        // No emitted code point may exceed the documented U+10FFFF invariant.
+        assert!(code_points(&[0xF4, 0x90, 0x80, 0x80]).all(|c| c.to_u32() <= 0x10_FFFF));
+    }
+
+    #[test]
+    fn code_points_combines_and_preserves_surrogates() {
+        let pair = [0xED, 0xA0, 0x81, 0xED, 0xB0, 0x80];
+        assert_eq!(
+            code_points(&pair).collect::<Vec<_>>(),
+            vec![CodePoint::from_char('\u{10400}')]
+        );
+
+        let lone = [0xED, 0xA0, 0x81];
+        assert_eq!(
+            code_points(&lone).collect::<Vec<_>>(),
+            vec![CodePoint::from_u32(0xD801).unwrap()]
+        );
+    }
+
+    #[test]
+    fn dfa_decodes_surrogate_pair() {
+        let data = [0xED, 0xA0, 0x81, 0xED, 0xB0, 0x80];
+        assert_eq!(decode(&data).unwrap(), "\u{10400}");
+    }
+
+    #[test]
+    fn dfa_rejects_invalid_cesu8() {
+        // A lone low surrogate and a pair truncated after the high surrogate are
+        // both invalid CESU-8.
+        assert!(decode(&[0xED, 0xB0, 0x80]).is_err());
+        assert!(decode(&[0xED, 0xA0, 0x81, 0xED]).is_err());
+        // A high surrogate followed by a non-surrogate three-byte scalar.
+        assert!(decode(&[0xED, 0xA0, 0x81, 0xE0, 0xA0, 0x80]).is_err());
+    }
+
+    #[test]
+    fn encode_decode_round_trips() {
+        let str = "A\u{00E9}\u{3042}\u{FFFF}\u{10400}z";
+        let encoded = encode(str);
+        assert_eq!(decode(&encoded).unwrap(), str);
+    }
+
+    #[test]
+    fn is_valid_tracks_char_width() {
+        assert!(is_valid("Hello, \u{FFFF}!"));
+        assert!(!is_valid("\u{10000}"));
+    }
+
+    #[test]
+    fn lossy_borrows_valid_input() {
+        assert!(matches!(decode_lossy(b"Hello, world!"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn lossy_replaces_overlong_and_truncation() {
+        // A stray continuation byte becomes a single replacement character.
+        assert_eq!(decode_lossy(&[0x80]).as_ref(), "\u{FFFD}");
+        // An overlong two-byte NUL is one malformed lead plus one stray
+        // continuation, i.e. two maximal subparts.
+        assert_eq!(decode_lossy(&[0xC0, 0x80]).as_ref(), "\u{FFFD}\u{FFFD}");
+        // A surrogate pair truncated at the end of input is a single subpart.
+        assert_eq!(decode_lossy(&[0xED, 0xA0, 0x81]).as_ref(), "\u{FFFD}");
+        // Scanning resumes after a malformed sequence rather than discarding the
+        // rest of the input.
+        assert_eq!(
+            decode_lossy(&[0x41, 0x80, 0xED, 0xA0, 0x81, 0xED, 0xB0, 0x80, 0x42]).as_ref(),
+            "A\u{FFFD}\u{10400}B"
+        );
+    }
+
+    #[test]
+    fn streaming_surrogate_pair_byte_at_a_time() {
+        // Feeding the six-byte pair one byte per chunk exercises a split at every
+        // internal boundary.
+        let data = [0xED, 0xA0, 0x81, 0xED, 0xB0, 0x80];
+        let mut decoder = Cesu8Decoder::new();
+        let mut out = String::new();
+        for &byte in &data {
+            decoder.decode_chunk(&[byte], &mut out).unwrap();
+        }
+        decoder.finish().unwrap();
+        assert_eq!(out, "\u{10400}");
+    }
+
+    #[test]
+    fn streaming_finish_errors_on_incomplete_sequence() {
+        let mut decoder = Cesu8Decoder::new();
+        let mut out = String::new();
+        decoder.decode_chunk(&[0xED, 0xA0, 0x81], &mut out).unwrap();
+        assert!(decoder.finish().is_err());
+    }
+
+    #[test]
+    fn streaming_errors_on_invalid_byte() {
+        let mut decoder = Cesu8Decoder::new();
+        let mut out = String::new();
+        assert!(decoder.decode_chunk(&[0xED, 0xB0, 0x80], &mut out).is_err());
+    }
+}